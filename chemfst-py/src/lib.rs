@@ -2,8 +2,9 @@ use pyo3::prelude::*;
 use pyo3::wrap_pyfunction;
 use pyo3::exceptions::{PyFileNotFoundError, PyRuntimeError};
 use std::path::Path;
+use std::sync::Arc;
 use memmap2::Mmap;
-use fst::Set;
+use fst::{Map, Set};
 use log::{info, debug, error};
 
 /// Python module for ChemFST: A high-performance chemical name search library using Finite State Transducers
@@ -13,7 +14,12 @@ fn chemfst(_py: Python, m: &Bound<'_, PyModule>) -> PyResult<()> {
     pyo3_log::init();
 
     m.add_class::<ChemicalFST>()?;
+    m.add_class::<ChemicalFSTMap>()?;
+    m.add_class::<PrefixSearchIter>()?;
+    m.add_class::<SubstringSearchIter>()?;
+    m.add_class::<FuzzySearchIter>()?;
     m.add_function(wrap_pyfunction!(build_fst, m)?)?;
+    m.add_function(wrap_pyfunction!(build_fst_map, m)?)?;
     m.add("__doc__", "ChemFST Python bindings for high-performance chemical name searching using Finite State Transducers (FSTs).")?;
     Ok(())
 }
@@ -51,13 +57,46 @@ fn build_fst(input_path: &str, fst_path: &str) -> PyResult<()> {
     Ok(())
 }
 
+/// Creates a weighted FST Map from `name<TAB>weight` lines in a text file.
+///
+/// Args:
+///     input_path: Path to a text file containing `name<TAB>weight` lines, one per line
+///     fst_path: Path where the FST map index file will be saved
+///
+/// Returns:
+///     None
+///
+/// Raises:
+///     FileNotFoundError: If the input file cannot be found
+///     RuntimeError: If there's an error building the FST map
+#[pyfunction]
+fn build_fst_map(input_path: &str, fst_path: &str) -> PyResult<()> {
+    info!("Python: build_fst_map called with input='{}', output='{}'", input_path, fst_path);
+
+    if !Path::new(input_path).exists() {
+        error!("Python: Input file not found: {}", input_path);
+        return Err(PyFileNotFoundError::new_err(format!(
+            "Input file not found: {}",
+            input_path
+        )));
+    }
+
+    ::chemfst::build_fst_map(input_path, fst_path).map_err(|e| {
+        error!("Python: Failed to build FST map: {}", e);
+        PyRuntimeError::new_err(format!("Failed to build FST map: {}", e))
+    })?;
+
+    info!("Python: Successfully completed build_fst_map");
+    Ok(())
+}
+
 /// ChemicalFST provides efficient searching of chemical names using Finite State Transducers.
 ///
 /// This class provides methods for prefix-based autocomplete and substring searching
 /// through large chemical name databases with high performance.
 #[pyclass(name = "ChemicalFST")]
 struct ChemicalFST {
-    set: Set<Mmap>,
+    set: Arc<Set<Mmap>>,
 }
 
 #[pymethods]
@@ -91,7 +130,7 @@ impl ChemicalFST {
         })?;
 
         info!("Python: Successfully created ChemicalFST instance");
-        Ok(Self { set })
+        Ok(Self { set: Arc::new(set) })
     }
 
     /// Find chemical names starting with a specified prefix.
@@ -137,6 +176,103 @@ impl ChemicalFST {
         Ok(results)
     }
 
+    /// Find chemical names within a given edit distance of a query, tolerating typos.
+    ///
+    /// Args:
+    ///     query: The (possibly misspelled) name to search for
+    ///     max_distance: Maximum edit distance to tolerate (default: 2, capped at 3)
+    ///     max_results: Maximum number of results to return (default: 100)
+    ///
+    /// Returns:
+    ///     list: A list of (name, distance) tuples, in FST (lexicographic) order, not
+    ///         sorted by distance. Sort by the `distance` element yourself if you need
+    ///         the closest matches first.
+    #[pyo3(signature = (query, max_distance=None, max_results=None))]
+    fn fuzzy_search(
+        &self,
+        query: &str,
+        max_distance: Option<usize>,
+        max_results: Option<usize>,
+    ) -> Vec<(String, usize)> {
+        let max_distance = max_distance.unwrap_or(2);
+        let max_results = max_results.unwrap_or(100);
+        debug!(
+            "Python: fuzzy_search called with query='{}', max_distance={}, max_results={}",
+            query, max_distance, max_results
+        );
+
+        let results = ::chemfst::fuzzy_search(&self.set, query, max_distance, max_results);
+
+        info!("Python: fuzzy_search completed, returning {} results", results.len());
+        results
+    }
+
+    /// Find chemical names starting with a specified prefix, lazily.
+    ///
+    /// Unlike `prefix_search`, this doesn't build a full list up front: it returns an
+    /// iterator backed directly by the FST stream, so `for name in fst.prefix_search_iter("a"):`
+    /// can `break` early without paying for the rest of the matches.
+    ///
+    /// Args:
+    ///     prefix: The prefix to search for
+    ///
+    /// Returns:
+    ///     PrefixSearchIter: An iterator of matching chemical names
+    fn prefix_search_iter(&self, prefix: &str) -> PrefixSearchIter {
+        debug!("Python: prefix_search_iter called with prefix='{}'", prefix);
+        let owner = Arc::clone(&self.set);
+        // SAFETY: see the module-level note on self-referential iterators.
+        let inner: ::chemfst::PrefixSearchIter<'static> =
+            unsafe { std::mem::transmute(::chemfst::prefix_search_iter(&owner, prefix)) };
+        PrefixSearchIter { _owner: owner, inner }
+    }
+
+    /// Find chemical names containing a specified substring, lazily.
+    ///
+    /// Unlike `substring_search`, this doesn't build a full list up front: it returns an
+    /// iterator backed directly by the FST stream, so callers can stop as soon as they
+    /// have enough matches.
+    ///
+    /// Args:
+    ///     substring: The substring to search for
+    ///
+    /// Returns:
+    ///     SubstringSearchIter: An iterator of matching chemical names
+    fn substring_search_iter(&self, substring: &str) -> SubstringSearchIter {
+        debug!("Python: substring_search_iter called with substring='{}'", substring);
+        let owner = Arc::clone(&self.set);
+        // SAFETY: see the module-level note on self-referential iterators.
+        let inner: ::chemfst::SubstringSearchIter<'static> =
+            unsafe { std::mem::transmute(::chemfst::substring_search_iter(&owner, substring)) };
+        SubstringSearchIter { _owner: owner, inner }
+    }
+
+    /// Find chemical names within a given edit distance of a query, lazily.
+    ///
+    /// Unlike `fuzzy_search`, this doesn't build a full list up front: it returns an
+    /// iterator backed directly by the Levenshtein automaton's FST stream.
+    ///
+    /// Args:
+    ///     query: The (possibly misspelled) name to search for
+    ///     max_distance: Maximum edit distance to tolerate (default: 2, capped at 3)
+    ///
+    /// Returns:
+    ///     FuzzySearchIter: An iterator of (name, distance) tuples
+    #[pyo3(signature = (query, max_distance=None))]
+    fn fuzzy_search_iter(&self, query: &str, max_distance: Option<usize>) -> FuzzySearchIter {
+        let max_distance = max_distance.unwrap_or(2);
+        debug!(
+            "Python: fuzzy_search_iter called with query='{}', max_distance={}",
+            query, max_distance
+        );
+        let owner = Arc::clone(&self.set);
+        // SAFETY: see the module-level note on self-referential iterators.
+        let inner: ::chemfst::FuzzySearchIter<'static> = unsafe {
+            std::mem::transmute(::chemfst::fuzzy_search_iter(&owner, query, max_distance))
+        };
+        FuzzySearchIter { _owner: owner, inner }
+    }
+
     /// Return a string representation of the ChemicalFST instance.
     fn __repr__(&self) -> PyResult<String> {
         Ok(format!("ChemicalFST(loaded=True)"))
@@ -174,3 +310,139 @@ impl ChemicalFST {
         Ok(count)
     }
 }
+
+/// ChemicalFSTMap provides weighted/ranked autocomplete over chemical names, backed by an
+/// FST map of usage scores built with `build_fst_map`.
+#[pyclass(name = "ChemicalFSTMap")]
+struct ChemicalFSTMap {
+    map: Map<Mmap>,
+}
+
+#[pymethods]
+impl ChemicalFSTMap {
+    /// Create a new ChemicalFSTMap instance by loading an FST map file.
+    ///
+    /// Args:
+    ///     fst_path: Path to the FST map index file
+    ///
+    /// Returns:
+    ///     ChemicalFSTMap: A new ChemicalFSTMap instance
+    ///
+    /// Raises:
+    ///     FileNotFoundError: If the FST map file cannot be found
+    ///     RuntimeError: If there's an error loading the FST map
+    #[new]
+    fn new(fst_path: &str) -> PyResult<Self> {
+        info!("Python: Creating new ChemicalFSTMap instance from: {}", fst_path);
+
+        if !Path::new(fst_path).exists() {
+            error!("Python: FST map file not found: {}", fst_path);
+            return Err(PyFileNotFoundError::new_err(format!(
+                "FST map file not found: {}",
+                fst_path
+            )));
+        }
+
+        let map = ::chemfst::load_fst_map(fst_path).map_err(|e| {
+            error!("Python: Failed to load FST map: {}", e);
+            PyRuntimeError::new_err(format!("Failed to load FST map: {}", e))
+        })?;
+
+        info!("Python: Successfully created ChemicalFSTMap instance");
+        Ok(Self { map })
+    }
+
+    /// Find the top-k highest-weighted chemical names starting with a specified prefix.
+    ///
+    /// Args:
+    ///     prefix: The prefix to search for
+    ///     k: Maximum number of results to return (default: 10)
+    ///
+    /// Returns:
+    ///     list: A list of (name, weight) tuples, highest weight first
+    #[pyo3(signature = (prefix, k=None))]
+    fn ranked_prefix_search(&self, prefix: &str, k: Option<usize>) -> Vec<(String, u64)> {
+        let k = k.unwrap_or(10);
+        debug!("Python: ranked_prefix_search called with prefix='{}', k={}", prefix, k);
+
+        let results = ::chemfst::ranked_prefix_search(&self.map, prefix, k);
+
+        info!("Python: ranked_prefix_search completed, returning {} results", results.len());
+        results
+    }
+
+    /// Return a string representation of the ChemicalFSTMap instance.
+    fn __repr__(&self) -> PyResult<String> {
+        Ok("ChemicalFSTMap(loaded=True)".to_string())
+    }
+
+    /// Return a string representation of the ChemicalFSTMap instance.
+    fn __str__(&self) -> PyResult<String> {
+        Ok("ChemicalFSTMap - Weighted chemical name autocomplete using Finite State Transducers".to_string())
+    }
+}
+
+// The iterator classes below wrap a `chemfst` stream whose lifetime borrows from the
+// `Arc<Set<Mmap>>` it was built from. Each class stores that `Arc`
+// alongside the stream and transmutes the borrow to `'static`: since the data behind an
+// `Arc` never moves for the lifetime of any clone of it, the borrow stays valid as long
+// as `_owner` is not dropped before `inner`. Rust drops struct fields in declaration
+// order, so `inner` is declared *before* `_owner` in every struct below to guarantee the
+// borrowing stream is torn down first. Do not reorder these fields.
+
+/// A Python-iterable wrapper around [`chemfst::PrefixSearchIter`], returned by
+/// `ChemicalFST.prefix_search_iter`.
+#[pyclass]
+struct PrefixSearchIter {
+    inner: ::chemfst::PrefixSearchIter<'static>,
+    _owner: Arc<Set<Mmap>>,
+}
+
+#[pymethods]
+impl PrefixSearchIter {
+    fn __iter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __next__(mut slf: PyRefMut<'_, Self>) -> Option<String> {
+        slf.inner.next()
+    }
+}
+
+/// A Python-iterable wrapper around [`chemfst::SubstringSearchIter`], returned by
+/// `ChemicalFST.substring_search_iter`.
+#[pyclass]
+struct SubstringSearchIter {
+    inner: ::chemfst::SubstringSearchIter<'static>,
+    _owner: Arc<Set<Mmap>>,
+}
+
+#[pymethods]
+impl SubstringSearchIter {
+    fn __iter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __next__(mut slf: PyRefMut<'_, Self>) -> Option<String> {
+        slf.inner.next()
+    }
+}
+
+/// A Python-iterable wrapper around [`chemfst::FuzzySearchIter`], returned by
+/// `ChemicalFST.fuzzy_search_iter`.
+#[pyclass]
+struct FuzzySearchIter {
+    inner: ::chemfst::FuzzySearchIter<'static>,
+    _owner: Arc<Set<Mmap>>,
+}
+
+#[pymethods]
+impl FuzzySearchIter {
+    fn __iter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __next__(mut slf: PyRefMut<'_, Self>) -> Option<(String, usize)> {
+        slf.inner.next()
+    }
+}
@@ -0,0 +1,188 @@
+//! [`fst::Automaton`] implementations that let searches prune the FST's trie
+//! directly instead of scanning every key in the set.
+
+use fst::Automaton;
+
+/// A Levenshtein automaton that matches any key within a bounded edit
+/// distance of a query string.
+///
+/// Each state holds the query's current "characteristic vector" row:
+/// `row[i]` is the minimal edit distance between the query's first `i` bytes
+/// and the input bytes consumed so far. A state is accepting once its last
+/// entry is within `max_distance`, and a branch is pruned entirely (`None`)
+/// as soon as every entry in the row exceeds `max_distance`, since no
+/// cheaper continuation is possible from there.
+///
+/// The distance is computed over UTF-8 *bytes*, not Unicode codepoints: a single
+/// mistyped multi-byte character (e.g. a letter with an accent) counts as multiple
+/// byte-level edits rather than one. For names that are mostly ASCII this matches
+/// intuition; for names with non-ASCII characters, callers may need a larger
+/// `max_distance` than the number of "typos" they want to tolerate would suggest.
+#[derive(Clone, Debug)]
+pub struct Levenshtein {
+    query: Vec<u8>,
+    max_distance: usize,
+}
+
+/// The largest edit distance we'll build an automaton for. Each additional
+/// distance roughly widens the reachable state space, so we bound it to keep
+/// automaton construction and traversal cheap.
+pub const MAX_FUZZY_DISTANCE: usize = 3;
+
+impl Levenshtein {
+    /// Builds a Levenshtein automaton for `query` bounded to `max_distance`
+    /// edits (insertions, deletions, substitutions). `max_distance` is
+    /// clamped to [`MAX_FUZZY_DISTANCE`].
+    pub fn new(query: &str, max_distance: usize) -> Self {
+        Levenshtein {
+            query: query.as_bytes().to_vec(),
+            max_distance: max_distance.min(MAX_FUZZY_DISTANCE),
+        }
+    }
+}
+
+impl Automaton for Levenshtein {
+    /// `None` once the row can no longer reach an accepting state, so the
+    /// whole branch can be skipped.
+    type State = Option<Vec<usize>>;
+
+    fn start(&self) -> Self::State {
+        Some((0..=self.query.len()).collect())
+    }
+
+    fn is_match(&self, state: &Self::State) -> bool {
+        match state {
+            Some(row) => *row.last().unwrap() <= self.max_distance,
+            None => false,
+        }
+    }
+
+    fn can_match(&self, state: &Self::State) -> bool {
+        state.is_some()
+    }
+
+    fn accept(&self, state: &Self::State, byte: u8) -> Self::State {
+        let row = state.as_ref()?;
+
+        // next[0] is the distance between the empty query prefix and the input
+        // consumed so far including `byte`, i.e. one more insertion than the
+        // previous empty-prefix cost.
+        let mut next = Vec::with_capacity(row.len());
+        next.push(row[0] + 1);
+        for (i, &query_byte) in self.query.iter().enumerate() {
+            let substitution_cost = row[i] + usize::from(query_byte != byte);
+            let deletion_cost = row[i + 1] + 1;
+            let insertion_cost = next[i] + 1;
+            next.push(substitution_cost.min(deletion_cost).min(insertion_cost));
+        }
+
+        if next.iter().copied().min().unwrap() > self.max_distance {
+            None
+        } else {
+            Some(next)
+        }
+    }
+}
+
+/// Computes the Levenshtein (edit) distance between two strings, operating
+/// on bytes. Used to report the distance of each [`Levenshtein`] match after
+/// the automaton has already pruned the search to keys within `max_distance`.
+#[must_use]
+pub fn edit_distance(a: &str, b: &str) -> usize {
+    let a = a.as_bytes();
+    let b = b.as_bytes();
+
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for (i, &a_byte) in a.iter().enumerate() {
+        let mut prev_diagonal = row[0];
+        row[0] = i + 1;
+        for (j, &b_byte) in b.iter().enumerate() {
+            let above = row[j + 1];
+            let substitution_cost = prev_diagonal + usize::from(a_byte != b_byte);
+            row[j + 1] = substitution_cost.min(above + 1).min(row[j] + 1);
+            prev_diagonal = above;
+        }
+    }
+
+    row[b.len()]
+}
+
+/// A DFA equivalent to the pattern `.*substring.*`, built with a KMP failure
+/// function so a failed match at state `i` falls back to the longest proper
+/// suffix of what's been consumed that is still a prefix of `substring`,
+/// rather than restarting from scratch.
+///
+/// State `i` means "the longest suffix of the bytes read so far that is also
+/// a prefix of `substring` has length `i`". State `substring.len()` is the
+/// accepting sink and loops on every byte, since once matched, any further
+/// input still contains the substring.
+#[derive(Clone, Debug)]
+pub struct Infix {
+    substring: Vec<u8>,
+    failure: Vec<usize>,
+}
+
+impl Infix {
+    /// Builds an infix automaton that matches (case-insensitively) any key
+    /// containing `substring`.
+    ///
+    /// Case folding is ASCII-only: each byte is compared with
+    /// [`u8::to_ascii_lowercase`] as it's consumed from the FST, so non-ASCII
+    /// letters (e.g. accented characters) are matched byte-for-byte rather than
+    /// case-folded. `substring` is folded the same way here so the two sides
+    /// agree.
+    #[must_use]
+    pub fn new(substring: &str) -> Self {
+        let substring = substring.as_bytes().to_ascii_lowercase();
+        let failure = build_failure_function(&substring);
+        Infix { substring, failure }
+    }
+}
+
+/// Standard KMP failure function: `failure[i]` is the length of the longest
+/// proper prefix of `pattern[..=i]` that is also a suffix of it.
+fn build_failure_function(pattern: &[u8]) -> Vec<usize> {
+    let mut failure = vec![0usize; pattern.len()];
+    let mut matched = 0;
+    for i in 1..pattern.len() {
+        while matched > 0 && pattern[matched] != pattern[i] {
+            matched = failure[matched - 1];
+        }
+        if pattern[matched] == pattern[i] {
+            matched += 1;
+        }
+        failure[i] = matched;
+    }
+    failure
+}
+
+impl Automaton for Infix {
+    /// Position within `substring` of the longest match ending at the
+    /// current input byte; `substring.len()` once the whole substring has
+    /// been seen.
+    type State = usize;
+
+    fn start(&self) -> Self::State {
+        0
+    }
+
+    fn is_match(&self, state: &Self::State) -> bool {
+        *state == self.substring.len()
+    }
+
+    fn accept(&self, state: &Self::State, byte: u8) -> Self::State {
+        if *state == self.substring.len() {
+            return *state;
+        }
+
+        let byte = byte.to_ascii_lowercase();
+        let mut matched = *state;
+        while matched > 0 && self.substring[matched] != byte {
+            matched = self.failure[matched - 1];
+        }
+        if self.substring[matched] == byte {
+            matched += 1;
+        }
+        matched
+    }
+}
@@ -0,0 +1,193 @@
+//! `chemfst` is a standalone command-line utility for building and querying
+//! `ChemFST` indexes without writing any code.
+//!
+//! ```text
+//! chemfst build <input.txt> <index.fst>
+//! chemfst prefix <index.fst> <query> [--max-results N] [--preload] [--format plain|json|tsv]
+//! chemfst substring <index.fst> <query> [--max-results N] [--preload] [--format plain|json|tsv]
+//! chemfst fuzzy <index.fst> <query> [--max-distance N] [--max-results N] [--preload] [--format plain|json|tsv]
+//! ```
+//!
+//! Logging verbosity follows ripgrep's convention: pass `-v` for debug logging or `-vv`
+//! for trace logging.
+
+use chemfst::{build_fst_set, fuzzy_search, load_fst_set, preload_fst_set, prefix_search, substring_search};
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use fst::Set;
+use memmap2::Mmap;
+use std::error::Error;
+use std::process::ExitCode;
+
+#[derive(Parser)]
+#[command(name = "chemfst", version, about = "Build and search chemical name indexes using Finite State Transducers")]
+struct Cli {
+    /// Increase logging verbosity (-v for debug, -vv for trace)
+    #[arg(short, long, action = clap::ArgAction::Count, global = true)]
+    verbose: u8,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Build an FST index from a newline-delimited text file of chemical names
+    Build {
+        /// Text file containing one chemical name per line
+        input: String,
+        /// Path where the FST index will be written
+        index: String,
+    },
+    /// Find chemical names starting with a prefix
+    Prefix(QueryArgs),
+    /// Find chemical names containing a substring, via an automaton-driven infix search
+    Substring(QueryArgs),
+    /// Find chemical names within an edit distance of a (possibly misspelled) query
+    Fuzzy {
+        #[command(flatten)]
+        query: QueryArgs,
+
+        /// Maximum edit distance to tolerate, capped at `chemfst::MAX_FUZZY_DISTANCE`
+        #[arg(long, default_value_t = 2)]
+        max_distance: usize,
+    },
+}
+
+#[derive(Args)]
+struct QueryArgs {
+    /// Path to the FST index file
+    index: String,
+    /// The search term
+    query: String,
+
+    /// Maximum number of results to return
+    #[arg(long, default_value_t = 10)]
+    max_results: usize,
+
+    /// Memory-map and touch every page of the index before querying
+    #[arg(long)]
+    preload: bool,
+
+    /// Output format
+    #[arg(long, value_enum, default_value_t = OutputFormat::Plain)]
+    format: OutputFormat,
+}
+
+#[derive(Copy, Clone, ValueEnum)]
+enum OutputFormat {
+    Plain,
+    Json,
+    Tsv,
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    let default_level = match cli.verbose {
+        0 => "warn",
+        1 => "debug",
+        _ => "trace",
+    };
+    env_logger::Builder::from_env(env_logger::Env::default().default_filter_or(default_level)).init();
+
+    match run(cli.command) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("error: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run(command: Command) -> Result<(), Box<dyn Error>> {
+    match command {
+        Command::Build { input, index } => {
+            build_fst_set(&input, &index)?;
+            println!("Built FST index from '{}' at '{}'", input, index);
+        }
+        Command::Prefix(args) => {
+            let set = open_set(&args)?;
+            let results = prefix_search(&set, &args.query, args.max_results);
+            print_rows(results.into_iter().map(|name| (name, None)), args.format);
+        }
+        Command::Substring(args) => {
+            let set = open_set(&args)?;
+            let results = substring_search(&set, &args.query, args.max_results)?;
+            print_rows(results.into_iter().map(|name| (name, None)), args.format);
+        }
+        Command::Fuzzy { query, max_distance } => {
+            let set = open_set(&query)?;
+            let results = fuzzy_search(&set, &query.query, max_distance, query.max_results);
+            print_rows(
+                results.into_iter().map(|(name, distance)| (name, Some(distance))),
+                query.format,
+            );
+        }
+    }
+    Ok(())
+}
+
+fn open_set(args: &QueryArgs) -> Result<Set<Mmap>, Box<dyn Error>> {
+    let set = load_fst_set(&args.index)?;
+    if args.preload {
+        let count = preload_fst_set(&set)?;
+        log::info!("Preloaded {} entries from '{}'", count, args.index);
+    }
+    Ok(set)
+}
+
+/// Prints `(name, score)` rows in the requested format, where `score` is the fuzzy-search
+/// edit distance when present.
+fn print_rows(rows: impl Iterator<Item = (String, Option<usize>)>, format: OutputFormat) {
+    match format {
+        OutputFormat::Plain => {
+            for (name, score) in rows {
+                match score {
+                    Some(score) => println!("{} (distance {})", name, score),
+                    None => println!("{}", name),
+                }
+            }
+        }
+        OutputFormat::Tsv => {
+            for (name, score) in rows {
+                match score {
+                    Some(score) => println!("{}\t{}", name, score),
+                    None => println!("{}", name),
+                }
+            }
+        }
+        OutputFormat::Json => {
+            let mut first = true;
+            print!("[");
+            for (name, score) in rows {
+                if !first {
+                    print!(",");
+                }
+                first = false;
+                match score {
+                    Some(score) => print!("{{\"name\":{},\"distance\":{}}}", json_string(&name), score),
+                    None => print!("{{\"name\":{}}}", json_string(&name)),
+                }
+            }
+            println!("]");
+        }
+    }
+}
+
+/// Renders `s` as a JSON string literal, escaping the characters JSON requires.
+fn json_string(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
@@ -9,6 +9,9 @@
 //! - Memory-efficient indexing using Finite State Transducers
 //! - Extremely fast prefix-based searches (autocomplete)
 //! - Case-insensitive substring searches
+//! - Fuzzy (edit-distance) searches that tolerate typos
+//! - Weighted/ranked autocomplete via an [`fst::Map`] of usage scores
+//! - Lazy, iterator-based search variants that avoid materializing large result vectors
 //! - Memory-mapped file access for optimal performance
 //!
 //! ## Example
@@ -38,14 +41,25 @@
 //! }
 //! ```
 
-use fst::{IntoStreamer, Set, SetBuilder, Streamer};
+mod automaton;
+
+pub use automaton::MAX_FUZZY_DISTANCE;
+
+use automaton::{Infix, Levenshtein};
+use fst::{IntoStreamer, Map, MapBuilder, Set, SetBuilder, Streamer};
 use log::{debug, error, info};
 use memmap2::Mmap;
 
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
 use std::error::Error;
 use std::fs::{File, OpenOptions};
 use std::io::{BufRead, BufReader};
 
+/// The weight assigned to an entry in [`build_fst_map`] when its input line has no
+/// explicit `<TAB>weight` suffix.
+const DEFAULT_WEIGHT: u64 = 1;
+
 /// Creates an FST Set from a list of chemical names in a text file.
 ///
 /// This function reads chemical names from a text file (one name per line), sorts them
@@ -192,6 +206,170 @@ pub fn load_fst_set(fst_path: &str) -> Result<Set<Mmap>, Box<dyn Error>> {
     Ok(set)
 }
 
+/// Creates an FST Map from a list of weighted chemical names in a text file.
+///
+/// This function reads `name<TAB>weight` lines from a text file (one entry per line),
+/// sorts them by name (as required by the FST data structure), and builds an FST map
+/// index with the weight stored as each key's value. Lines with no `<TAB>weight` suffix,
+/// or whose weight fails to parse as a `u64`, are assigned [`DEFAULT_WEIGHT`]. If a name
+/// appears more than once, the entry with the highest weight wins. The index is saved to
+/// disk at the specified path.
+///
+/// Unlike [`build_fst_set`], this preserves relevance information so that
+/// [`ranked_prefix_search`] can surface the most commonly used names first instead of
+/// whatever sorts first alphabetically.
+///
+/// # Arguments
+///
+/// * `input_path` - Path to a text file containing `name<TAB>weight` lines, one per line
+/// * `fst_path` - Path where the FST index file will be saved
+///
+/// # Returns
+///
+/// * `Ok(())` on success
+/// * `Err(Box<dyn Error>)` if an error occurs during file operations or index building
+///
+/// # Errors
+///
+/// This function will return an error if:
+/// * The input file cannot be found or read
+/// * The output file cannot be created or written to
+/// * There is an issue building the FST index
+///
+/// # Example
+///
+/// ```no_run
+/// use chemfst::build_fst_map;
+///
+/// let result = build_fst_map("data/chemical_names_weighted.txt", "data/chemical_names.fstmap");
+/// assert!(result.is_ok());
+/// ```
+pub fn build_fst_map(input_path: &str, fst_path: &str) -> Result<(), Box<dyn Error>> {
+    info!("Building FST map from input file: {}", input_path);
+    debug!("Output FST map file: {}", fst_path);
+
+    let file = File::open(input_path).map_err(|e| {
+        error!("Failed to open input file '{}': {}", input_path, e);
+        e
+    })?;
+    let reader = BufReader::new(file);
+
+    let mut entries: Vec<(String, u64)> = Vec::new();
+    for line in reader.lines().map_while(Result::ok) {
+        let mut parts = line.splitn(2, '\t');
+        let name = match parts.next() {
+            Some(name) if !name.is_empty() => name.to_string(),
+            _ => continue,
+        };
+        let weight = parts
+            .next()
+            .and_then(|w| w.trim().parse::<u64>().ok())
+            .unwrap_or(DEFAULT_WEIGHT);
+        entries.push((name, weight));
+    }
+    info!("Read {} chemical name/weight entries from input file", entries.len());
+
+    // The fst crate requires sorted input. Break ties on duplicate names by weight,
+    // descending, so that when a name appears more than once in the input file the
+    // dedup pass below deterministically keeps the entry with the highest weight.
+    debug!("Sorting and deduplicating chemical name/weight entries");
+    entries.sort_unstable_by(|a, b| a.0.cmp(&b.0).then_with(|| b.1.cmp(&a.1)));
+    let original_count = entries.len();
+    entries.dedup_by(|a, b| a.0 == b.0);
+    let deduplicated_count = entries.len();
+
+    if original_count != deduplicated_count {
+        info!(
+            "Removed {} duplicate entries, {} unique names remaining",
+            original_count - deduplicated_count,
+            deduplicated_count
+        );
+    }
+
+    debug!("Creating FST map builder");
+    let wtr = File::create(fst_path).map_err(|e| {
+        error!("Failed to create output file '{}': {}", fst_path, e);
+        e
+    })?;
+    let mut builder = MapBuilder::new(wtr)?;
+
+    debug!("Inserting {} entries into FST map", entries.len());
+    for (i, (name, weight)) in entries.iter().enumerate() {
+        if i > 0 && i % 10000 == 0 {
+            debug!("Inserted {} / {} entries", i, entries.len());
+        }
+        builder.insert(name, *weight)?;
+    }
+
+    debug!("Finalizing FST map");
+    builder.finish()?;
+    info!(
+        "Successfully built FST map with {} entries at: {}",
+        deduplicated_count, fst_path
+    );
+    Ok(())
+}
+
+/// Memory maps an FST map from disk.
+///
+/// This function loads an FST map from disk using memory mapping, which provides
+/// efficient access to the index without loading the entire file into memory.
+///
+/// # Arguments
+///
+/// * `fst_path` - Path to the FST map index file
+///
+/// # Returns
+///
+/// * `Ok(Map<Mmap>)` - The memory-mapped FST map
+/// * `Err(Box<dyn Error>)` if the file cannot be opened or mapped
+///
+/// # Errors
+///
+/// This function will return an error if:
+/// * The FST file cannot be found or opened
+/// * The file cannot be memory-mapped
+/// * The file is not a valid FST map index
+///
+/// # Safety
+///
+/// This function uses `unsafe` to create a memory map of the file. It's safe as long as
+/// the file is not modified while the memory map is active.
+///
+/// # Example
+///
+/// ```no_run
+/// use chemfst::{build_fst_map, load_fst_map};
+///
+/// build_fst_map("data/chemical_names_weighted.txt", "data/chemical_names.fstmap").unwrap();
+/// let map = load_fst_map("data/chemical_names.fstmap").unwrap();
+/// ```
+pub fn load_fst_map(fst_path: &str) -> Result<Map<Mmap>, Box<dyn Error>> {
+    info!("Loading FST map from: {}", fst_path);
+
+    let file = OpenOptions::new().read(true).open(fst_path).map_err(|e| {
+        error!("Failed to open FST map file '{}': {}", fst_path, e);
+        e
+    })?;
+
+    debug!("Memory mapping FST map file");
+    let mmap = unsafe {
+        Mmap::map(&file).map_err(|e| {
+            error!("Failed to memory map FST map file '{}': {}", fst_path, e);
+            e
+        })?
+    };
+
+    debug!("Creating FST map from memory map");
+    let map = Map::new(mmap).map_err(|e| {
+        error!("Failed to create FST map from file '{}': {}", fst_path, e);
+        e
+    })?;
+
+    info!("Successfully loaded FST map from: {}", fst_path);
+    Ok(map)
+}
+
 /// Performs prefix-based autocomplete search.
 ///
 /// This function efficiently finds all chemical names in the FST set that start with the given prefix,
@@ -254,10 +432,284 @@ pub fn prefix_search(set: &Set<Mmap>, prefix: &str, max_results: usize) -> Vec<S
     results
 }
 
-/// Performs substring search using pattern matching on the FST set.
+/// A lazy iterator over prefix-search matches, returned by [`prefix_search_iter`].
+///
+/// Backed directly by the FST's range stream, so peak memory stays proportional to what
+/// the caller actually reads instead of the full match count.
+pub struct PrefixSearchIter<'s> {
+    stream: fst::set::Stream<'s>,
+}
+
+impl Iterator for PrefixSearchIter<'_> {
+    type Item = String;
+
+    fn next(&mut self) -> Option<String> {
+        loop {
+            let key = self.stream.next()?;
+            if let Ok(s) = String::from_utf8(key.to_vec()) {
+                return Some(s);
+            }
+        }
+    }
+}
+
+/// Performs prefix-based autocomplete search, returning a lazy iterator instead of a
+/// materialized `Vec`.
+///
+/// Unlike [`prefix_search`], this doesn't take a `max_results` bound or collect
+/// anything up front; the caller drives the FST stream by iterating (and can stop
+/// early, e.g. with `.take(n)`), which keeps a prefix like `"a"` against a
+/// multi-million-name database from forcing a large allocation.
+///
+/// # Arguments
+///
+/// * `set` - The FST Set to search in
+/// * `prefix` - The prefix to search for
+///
+/// # Example
+///
+/// ```no_run
+/// use chemfst::{load_fst_set, prefix_search_iter};
+///
+/// let set = load_fst_set("data/chemical_names.fst").unwrap();
+/// for chemical in prefix_search_iter(&set, "acet").take(10) {
+///     println!("Found: {}", chemical);
+/// }
+/// ```
+pub fn prefix_search_iter<'s>(set: &'s Set<Mmap>, prefix: &str) -> PrefixSearchIter<'s> {
+    debug!("Starting lazy prefix search for '{}'", prefix);
+
+    let stream = set
+        .range()
+        .ge(prefix)
+        .lt(format!("{}{}", prefix, char::MAX))
+        .into_stream();
+
+    PrefixSearchIter { stream }
+}
+
+/// Performs weighted/ranked autocomplete search over an FST map.
+///
+/// This function finds all chemical names in the FST map that start with the given
+/// prefix, then returns the top `k` by weight instead of the lexicographic order
+/// [`prefix_search`] returns. It streams every prefix match but only ever holds a
+/// min-heap of size `k`, so it doesn't materialize the full match set to sort it.
+///
+/// # Arguments
+///
+/// * `map` - The FST Map to search in, as built by [`build_fst_map`]
+/// * `prefix` - The prefix to search for
+/// * `k` - Maximum number of results to return
+///
+/// # Returns
+///
+/// A vector of `(name, weight)` pairs, highest weight first.
+///
+/// # Example
+///
+/// ```no_run
+/// use chemfst::{load_fst_map, ranked_prefix_search};
+///
+/// let map = load_fst_map("data/chemical_names.fstmap").unwrap();
+/// let results = ranked_prefix_search(&map, "acet", 10);
+/// for (chemical, weight) in results {
+///     println!("Found: {} (weight {})", chemical, weight);
+/// }
+/// ```
+#[must_use]
+pub fn ranked_prefix_search(map: &Map<Mmap>, prefix: &str, k: usize) -> Vec<(String, u64)> {
+    debug!("Starting ranked prefix search for '{}' with k={}", prefix, k);
+
+    let mut heap: BinaryHeap<Reverse<(u64, String)>> = BinaryHeap::with_capacity(k);
+    let mut stream = map
+        .range()
+        .ge(prefix)
+        .lt(format!("{}{}", prefix, char::MAX))
+        .into_stream();
+
+    let mut checked_count = 0;
+    while let Some((key, weight)) = stream.next() {
+        checked_count += 1;
+        let Ok(name) = String::from_utf8(key.to_vec()) else {
+            continue;
+        };
+
+        if heap.len() < k {
+            heap.push(Reverse((weight, name)));
+        } else if let Some(&Reverse((min_weight, _))) = heap.peek() {
+            if weight > min_weight {
+                heap.pop();
+                heap.push(Reverse((weight, name)));
+            }
+        }
+    }
+
+    let results: Vec<(String, u64)> = heap
+        .into_sorted_vec()
+        .into_iter()
+        .map(|Reverse((weight, name))| (name, weight))
+        .collect();
+
+    info!(
+        "Ranked prefix search for '{}' found {} results (checked {} entries)",
+        prefix,
+        results.len(),
+        checked_count
+    );
+    results
+}
+
+/// Performs fuzzy (edit-distance) search using a Levenshtein automaton.
+///
+/// This function finds all chemical names in the FST set within `max_distance` edits
+/// (insertions, deletions, substitutions) of `query`, up to a specified maximum number
+/// of results. Because the automaton is intersected with the FST's trie structure via
+/// `set.search(automaton)`, only branches that can still lead to a match are visited,
+/// rather than scanning every key in the set.
+///
+/// # Arguments
+///
+/// * `set` - The FST Set to search in
+/// * `query` - The (possibly misspelled) name to search for
+/// * `max_distance` - Maximum edit distance to tolerate, clamped to [`MAX_FUZZY_DISTANCE`]
+/// * `max_results` - Maximum number of results to return
+///
+/// # Returns
+///
+/// A vector of `(name, distance)` pairs, where `distance` is the computed edit distance
+/// between `query` and `name`, so callers can rank results by closeness.
+///
+/// # Example
+///
+/// ```no_run
+/// use chemfst::{load_fst_set, fuzzy_search};
+///
+/// let set = load_fst_set("data/chemical_names.fst").unwrap();
+/// let results = fuzzy_search(&set, "acetominophen", 2, 10);
+/// for (chemical, distance) in results {
+///     println!("Found: {} (distance {})", chemical, distance);
+/// }
+/// ```
+#[must_use]
+pub fn fuzzy_search(
+    set: &Set<Mmap>,
+    query: &str,
+    max_distance: usize,
+    max_results: usize,
+) -> Vec<(String, usize)> {
+    debug!(
+        "Starting fuzzy search for '{}' with max_distance={}, max_results={}",
+        query, max_distance, max_results
+    );
+
+    let automaton = Levenshtein::new(query, max_distance);
+
+    let mut results = Vec::new();
+    let mut stream = set.search(automaton).into_stream();
+    let mut checked_count = 0;
+
+    while let Some(key) = stream.next() {
+        checked_count += 1;
+        if results.len() >= max_results {
+            debug!("Reached max_results limit of {}", max_results);
+            break;
+        }
+        if let Ok(s) = String::from_utf8(key.to_vec()) {
+            let distance = automaton::edit_distance(query, &s);
+            debug!("Found match: {} (distance {})", s, distance);
+            results.push((s, distance));
+        }
+    }
+
+    info!(
+        "Fuzzy search for '{}' found {} results (visited {} matching states)",
+        query,
+        results.len(),
+        checked_count
+    );
+    results
+}
+
+/// A lazy iterator over fuzzy-search matches, returned by [`fuzzy_search_iter`].
+///
+/// Backed directly by the Levenshtein automaton's FST stream, so peak memory stays
+/// proportional to what the caller actually reads instead of the full match count.
+pub struct FuzzySearchIter<'s> {
+    query: String,
+    stream: fst::set::Stream<'s, Levenshtein>,
+}
+
+impl Iterator for FuzzySearchIter<'_> {
+    type Item = (String, usize);
+
+    fn next(&mut self) -> Option<(String, usize)> {
+        loop {
+            let key = self.stream.next()?;
+            if let Ok(s) = String::from_utf8(key.to_vec()) {
+                let distance = automaton::edit_distance(&self.query, &s);
+                return Some((s, distance));
+            }
+        }
+    }
+}
+
+/// Performs fuzzy (edit-distance) search, returning a lazy iterator instead of a
+/// materialized `Vec`.
+///
+/// Unlike [`fuzzy_search`], this doesn't take a `max_results` bound; the caller drives
+/// the FST stream by iterating (and can stop early, e.g. with `.take(n)`).
+///
+/// # Arguments
+///
+/// * `set` - The FST Set to search in
+/// * `query` - The (possibly misspelled) name to search for
+/// * `max_distance` - Maximum edit distance to tolerate, clamped to [`MAX_FUZZY_DISTANCE`]
+///
+/// # Example
+///
+/// ```no_run
+/// use chemfst::{load_fst_set, fuzzy_search_iter};
+///
+/// let set = load_fst_set("data/chemical_names.fst").unwrap();
+/// for (chemical, distance) in fuzzy_search_iter(&set, "acetominophen", 2).take(10) {
+///     println!("Found: {} (distance {})", chemical, distance);
+/// }
+/// ```
+pub fn fuzzy_search_iter<'s>(
+    set: &'s Set<Mmap>,
+    query: &str,
+    max_distance: usize,
+) -> FuzzySearchIter<'s> {
+    debug!(
+        "Starting lazy fuzzy search for '{}' with max_distance={}",
+        query, max_distance
+    );
+
+    let automaton = Levenshtein::new(query, max_distance);
+    let stream = set.search(automaton).into_stream();
+
+    FuzzySearchIter {
+        query: query.to_string(),
+        stream,
+    }
+}
+
+/// Performs substring search using an automaton-driven infix match on the FST set.
 ///
 /// This function finds all chemical names in the FST set that contain the given substring,
-/// up to a specified maximum number of results. The search is case-insensitive.
+/// up to a specified maximum number of results. The search is case-insensitive for ASCII
+/// letters; non-ASCII letters are matched byte-for-byte rather than case-folded.
+///
+/// Note for callers upgrading from the old linear `.to_lowercase().contains(...)` scan:
+/// that implementation case-folded the full Unicode range, so an accented name's
+/// upper/lower-case variants matched each other. This automaton-driven version only
+/// folds ASCII, as a deliberate, signed-off trade-off for running the search as an FST
+/// intersection instead of a full scan. If your data relies on non-ASCII case folding,
+/// normalize case in the input file before building the index.
+///
+/// Rather than walking every key in the set, the substring is compiled into a small DFA
+/// equivalent to the pattern `.*substring.*` and intersected with the FST's trie via
+/// `set.search(automaton)`, so only branches that can still lead to a match are explored.
 ///
 /// # Arguments
 ///
@@ -296,12 +748,10 @@ pub fn substring_search(
         substring, max_results
     );
 
-    // We'll do this manually instead of using fst-regex
-    // No need for regex pattern as we're doing direct substring matching
-    let substring_lower = substring.to_lowercase();
+    let automaton = Infix::new(substring);
 
     let mut results = Vec::new();
-    let mut stream = set.stream().into_stream();
+    let mut stream = set.search(automaton).into_stream();
     let mut checked_count = 0;
 
     while let Some(key) = stream.next() {
@@ -320,11 +770,8 @@ pub fn substring_search(
         }
 
         if let Ok(s) = String::from_utf8(key.to_vec()) {
-            // Manually check if the string contains our substring
-            if s.to_lowercase().contains(&substring_lower) {
-                debug!("Found match: {}", s);
-                results.push(s);
-            }
+            debug!("Found match: {}", s);
+            results.push(s);
         }
     }
 
@@ -337,6 +784,56 @@ pub fn substring_search(
     Ok(results)
 }
 
+/// A lazy iterator over substring-search matches, returned by [`substring_search_iter`].
+///
+/// Backed directly by the infix automaton's FST stream, so peak memory stays
+/// proportional to what the caller actually reads instead of the full match count.
+pub struct SubstringSearchIter<'s> {
+    stream: fst::set::Stream<'s, Infix>,
+}
+
+impl Iterator for SubstringSearchIter<'_> {
+    type Item = String;
+
+    fn next(&mut self) -> Option<String> {
+        loop {
+            let key = self.stream.next()?;
+            if let Ok(s) = String::from_utf8(key.to_vec()) {
+                return Some(s);
+            }
+        }
+    }
+}
+
+/// Performs substring search, returning a lazy iterator instead of a materialized `Vec`.
+///
+/// Unlike [`substring_search`], this doesn't take a `max_results` bound; the caller
+/// drives the FST stream by iterating (and can stop early, e.g. with `.take(n)`).
+///
+/// # Arguments
+///
+/// * `set` - The FST Set to search in
+/// * `substring` - The substring to search for
+///
+/// # Example
+///
+/// ```no_run
+/// use chemfst::{load_fst_set, substring_search_iter};
+///
+/// let set = load_fst_set("data/chemical_names.fst").unwrap();
+/// for chemical in substring_search_iter(&set, "enz").take(10) {
+///     println!("Found: {}", chemical);
+/// }
+/// ```
+pub fn substring_search_iter<'s>(set: &'s Set<Mmap>, substring: &str) -> SubstringSearchIter<'s> {
+    debug!("Starting lazy substring search for '{}'", substring);
+
+    let automaton = Infix::new(substring);
+    let stream = set.search(automaton).into_stream();
+
+    SubstringSearchIter { stream }
+}
+
 /// Forces the operating system to load all pages of the FST into memory.
 ///
 /// This function traverses the entire FST, causing all pages of the memory-mapped file
@@ -1,6 +1,11 @@
-use fast_search::{build_fst_set, load_fst_set, prefix_search, substring_search};
+use chemfst::{
+    build_fst_map, build_fst_set, fuzzy_search, fuzzy_search_iter, load_fst_map, load_fst_set,
+    prefix_search, prefix_search_iter, ranked_prefix_search, substring_search,
+    substring_search_iter,
+};
 use std::io::Write;
 use std::path::PathBuf;
+use std::process::Command;
 use tempfile::NamedTempFile;
 
 // Helper function to create test data files
@@ -93,3 +98,221 @@ fn test_substring_search() {
     let limited_results = substring_search(&set, "e", 2).unwrap();
     assert_eq!(limited_results.len(), 2);
 }
+
+#[test]
+fn test_substring_search_non_ascii_is_byte_literal() {
+    // Case folding is ASCII-only: accented letters are matched byte-for-byte, not
+    // case-folded, so "éther" does not match a stored "ÉTHER".
+    let mut input_file = NamedTempFile::new().unwrap();
+    writeln!(input_file, "ÉTHER").unwrap();
+    writeln!(input_file, "ether").unwrap();
+    let input_path = input_file.path().to_path_buf();
+    std::mem::forget(input_file);
+
+    let fst_file = NamedTempFile::new().unwrap();
+    let fst_path = fst_file.path().to_path_buf();
+    std::mem::forget(fst_file);
+
+    build_fst_set(input_path.to_str().unwrap(), fst_path.to_str().unwrap()).unwrap();
+    let set = load_fst_set(fst_path.to_str().unwrap()).unwrap();
+
+    // ASCII case folding still works.
+    let ascii_results = substring_search(&set, "ETH", 10).unwrap();
+    assert_eq!(ascii_results, vec!["ether".to_string()]);
+
+    // The accented variant is not found by its lowercase form.
+    let non_ascii_results = substring_search(&set, "éther", 10).unwrap();
+    assert_eq!(non_ascii_results.len(), 0);
+
+    // It is found by its exact-case form.
+    let exact_results = substring_search(&set, "ÉTHER", 10).unwrap();
+    assert_eq!(exact_results, vec!["ÉTHER".to_string()]);
+}
+
+#[test]
+fn test_fuzzy_search() {
+    let (input_path, fst_path) = create_test_data();
+
+    // Build the FST set
+    build_fst_set(input_path.to_str().unwrap(), fst_path.to_str().unwrap()).unwrap();
+
+    // Load the FST set
+    let set = load_fst_set(fst_path.to_str().unwrap()).unwrap();
+
+    // An exact match has distance 0
+    let exact = fuzzy_search(&set, "acetone", 2, 10);
+    assert!(exact.iter().any(|(name, distance)| name == "acetone" && *distance == 0));
+
+    // A single-character typo is still found within distance 1
+    let typo = fuzzy_search(&set, "acetne", 1, 10);
+    assert!(typo.iter().any(|(name, distance)| name == "acetone" && *distance == 1));
+
+    // Nothing within the bound is reported
+    let far = fuzzy_search(&set, "xyzxyzxyz", 1, 10);
+    assert_eq!(far.len(), 0);
+}
+
+#[test]
+fn test_fuzzy_search_max_results() {
+    // Several names a single edit away from a shared query, so max_results actually
+    // has something to truncate.
+    let mut input_file = NamedTempFile::new().unwrap();
+    writeln!(input_file, "acetane").unwrap();
+    writeln!(input_file, "acetine").unwrap();
+    writeln!(input_file, "acetone").unwrap();
+    writeln!(input_file, "benzene").unwrap();
+    let input_path = input_file.path().to_path_buf();
+    std::mem::forget(input_file);
+
+    let fst_file = NamedTempFile::new().unwrap();
+    let fst_path = fst_file.path().to_path_buf();
+    std::mem::forget(fst_file);
+
+    build_fst_set(input_path.to_str().unwrap(), fst_path.to_str().unwrap()).unwrap();
+    let set = load_fst_set(fst_path.to_str().unwrap()).unwrap();
+
+    let all_matches = fuzzy_search(&set, "acetxne", 1, 10);
+    assert_eq!(all_matches.len(), 3);
+
+    let limited = fuzzy_search(&set, "acetxne", 1, 2);
+    assert_eq!(limited.len(), 2);
+}
+
+#[test]
+fn test_ranked_prefix_search() {
+    let mut input_file = NamedTempFile::new().unwrap();
+    writeln!(input_file, "acetone\t5").unwrap();
+    writeln!(input_file, "acetaminophen\t50").unwrap();
+    writeln!(input_file, "acetic acid\t20").unwrap();
+    writeln!(input_file, "benzene\t1").unwrap();
+    let input_path = input_file.path().to_path_buf();
+    std::mem::forget(input_file);
+
+    let fst_file = NamedTempFile::new().unwrap();
+    let fst_path = fst_file.path().to_path_buf();
+    std::mem::forget(fst_file);
+
+    build_fst_map(input_path.to_str().unwrap(), fst_path.to_str().unwrap()).unwrap();
+    let map = load_fst_map(fst_path.to_str().unwrap()).unwrap();
+
+    // Highest weight first, regardless of lexicographic order.
+    let results = ranked_prefix_search(&map, "acet", 10);
+    assert_eq!(
+        results,
+        vec![
+            ("acetaminophen".to_string(), 50),
+            ("acetic acid".to_string(), 20),
+            ("acetone".to_string(), 5),
+        ]
+    );
+
+    // Top-k is respected.
+    let top1 = ranked_prefix_search(&map, "acet", 1);
+    assert_eq!(top1, vec![("acetaminophen".to_string(), 50)]);
+
+    // No matches for a prefix outside the set.
+    let empty = ranked_prefix_search(&map, "xyz", 10);
+    assert_eq!(empty.len(), 0);
+}
+
+#[test]
+fn test_build_fst_map_duplicate_name_keeps_highest_weight() {
+    let mut input_file = NamedTempFile::new().unwrap();
+    writeln!(input_file, "acetone\t5").unwrap();
+    writeln!(input_file, "acetone\t50").unwrap();
+    writeln!(input_file, "acetone\t20").unwrap();
+    let input_path = input_file.path().to_path_buf();
+    std::mem::forget(input_file);
+
+    let fst_file = NamedTempFile::new().unwrap();
+    let fst_path = fst_file.path().to_path_buf();
+    std::mem::forget(fst_file);
+
+    build_fst_map(input_path.to_str().unwrap(), fst_path.to_str().unwrap()).unwrap();
+    let map = load_fst_map(fst_path.to_str().unwrap()).unwrap();
+
+    let results = ranked_prefix_search(&map, "acetone", 10);
+    assert_eq!(results, vec![("acetone".to_string(), 50)]);
+}
+
+#[test]
+fn test_build_fst_map_default_weight() {
+    let mut input_file = NamedTempFile::new().unwrap();
+    writeln!(input_file, "acetone").unwrap();
+    let input_path = input_file.path().to_path_buf();
+    std::mem::forget(input_file);
+
+    let fst_file = NamedTempFile::new().unwrap();
+    let fst_path = fst_file.path().to_path_buf();
+    std::mem::forget(fst_file);
+
+    build_fst_map(input_path.to_str().unwrap(), fst_path.to_str().unwrap()).unwrap();
+    let map = load_fst_map(fst_path.to_str().unwrap()).unwrap();
+
+    let results = ranked_prefix_search(&map, "acetone", 10);
+    assert_eq!(results, vec![("acetone".to_string(), 1)]);
+}
+
+#[test]
+fn test_cli_build_and_prefix() {
+    let (input_path, fst_path) = create_test_data();
+
+    let build_status = Command::new(env!("CARGO_BIN_EXE_chemfst"))
+        .args(["build", input_path.to_str().unwrap(), fst_path.to_str().unwrap()])
+        .status()
+        .unwrap();
+    assert!(build_status.success());
+
+    let output = Command::new(env!("CARGO_BIN_EXE_chemfst"))
+        .args(["prefix", fst_path.to_str().unwrap(), "acet", "--format", "tsv"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.contains("acetone"));
+    assert!(stdout.contains("acetaminophen"));
+}
+
+#[test]
+fn test_prefix_search_iter_matches_prefix_search() {
+    let (input_path, fst_path) = create_test_data();
+    build_fst_set(input_path.to_str().unwrap(), fst_path.to_str().unwrap()).unwrap();
+    let set = load_fst_set(fst_path.to_str().unwrap()).unwrap();
+
+    let mut iter_results: Vec<String> = prefix_search_iter(&set, "acet").collect();
+    iter_results.sort();
+    let mut vec_results = prefix_search(&set, "acet", 10);
+    vec_results.sort();
+    assert_eq!(iter_results, vec_results);
+
+    // The iterator can be stopped early without consuming the rest of the matches.
+    let first_only: Vec<String> = prefix_search_iter(&set, "acet").take(1).collect();
+    assert_eq!(first_only.len(), 1);
+}
+
+#[test]
+fn test_substring_search_iter_matches_substring_search() {
+    let (input_path, fst_path) = create_test_data();
+    build_fst_set(input_path.to_str().unwrap(), fst_path.to_str().unwrap()).unwrap();
+    let set = load_fst_set(fst_path.to_str().unwrap()).unwrap();
+
+    let mut iter_results: Vec<String> = substring_search_iter(&set, "enz").collect();
+    iter_results.sort();
+    let mut vec_results = substring_search(&set, "enz", 10).unwrap();
+    vec_results.sort();
+    assert_eq!(iter_results, vec_results);
+}
+
+#[test]
+fn test_fuzzy_search_iter_matches_fuzzy_search() {
+    let (input_path, fst_path) = create_test_data();
+    build_fst_set(input_path.to_str().unwrap(), fst_path.to_str().unwrap()).unwrap();
+    let set = load_fst_set(fst_path.to_str().unwrap()).unwrap();
+
+    let mut iter_results: Vec<(String, usize)> = fuzzy_search_iter(&set, "acetne", 1).collect();
+    iter_results.sort();
+    let mut vec_results = fuzzy_search(&set, "acetne", 1, 10);
+    vec_results.sort();
+    assert_eq!(iter_results, vec_results);
+}